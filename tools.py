@@ -1,8 +1,11 @@
 import datetime
 import math
+import time
 import requests
+from urllib3.exceptions import ReadTimeoutError
 from bs4 import BeautifulSoup
 import json
+from urllib.parse import urljoin
 
 class Tool:
     def __init__(self, name: str, description: str, func: callable):
@@ -30,22 +33,63 @@ def calculate(expression: str):
     except Exception as e:
         return f"Error: {e}"
 
-def search_web(query: str, limit: int = 5):
+def search_web(query: str, limit: int = 5, connect_timeout: float = 5, read_timeout: float = 10, total_timeout: float = 30):
     """
     Search the web using Google.
     Args:
         query: The search query string.
         limit: Max results (default 5, max 10).
+        connect_timeout: Seconds to wait for the connection (default 5).
+        read_timeout: Seconds of inactivity allowed between bytes of the response (default 10).
+        total_timeout: Seconds allowed for the whole request, redirects and body included (default 30).
+            Checked before each redirect hop and body read, with every wait clamped to the time
+            left. DNS lookups and a server trickling its response headers are not covered.
     """
+    deadline = time.monotonic() + total_timeout
     try:
         limit = min(int(limit), 10)
         headers = {
             "User-Agent": "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36"
         }
-        response = requests.get("https://www.google.com/search", params={"q": query}, headers=headers)
-        response.raise_for_status()
-        
-        soup = BeautifulSoup(response.text, "html.parser")
+        url = "https://www.google.com/search"
+        params = {"q": query}
+        with requests.Session() as session:
+            # Follow redirects by hand so every hop counts against the total deadline
+            for _ in range(10):
+                remaining = deadline - time.monotonic()
+                if remaining <= 0:
+                    return f"Search error: total timeout after {total_timeout}s"
+                response = session.get(
+                    url,
+                    params=params,
+                    headers=headers,
+                    timeout=(min(connect_timeout, remaining), min(read_timeout, remaining)),
+                    stream=True,
+                    allow_redirects=False
+                )
+                if not response.is_redirect:
+                    break
+                response.close()
+                url = urljoin(response.url, response.headers["Location"])
+                params = None
+            else:
+                return "Search error: too many redirects"
+
+            with response:
+                response.raise_for_status()
+
+                # iter_content blocks until a whole chunk has arrived, so a slow trickle could
+                # outlast the deadline; read1 returns as soon as any bytes are available instead.
+                chunks = []
+                while True:
+                    if time.monotonic() > deadline:
+                        return f"Search error: total timeout after {total_timeout}s"
+                    chunk = response.raw.read1(8192, decode_content=True)
+                    if not chunk:
+                        break
+                    chunks.append(chunk)
+
+        soup = BeautifulSoup(b"".join(chunks), "html.parser", from_encoding=response.encoding)
         results = []
         
         for g in soup.find_all('div', class_='g'):
@@ -66,6 +110,14 @@ def search_web(query: str, limit: int = 5):
                     })
                     
         return json.dumps(results, indent=2)
+    except requests.exceptions.ConnectTimeout:
+        if time.monotonic() >= deadline:
+            return f"Search error: total timeout after {total_timeout}s"
+        return f"Search error: connect timeout after {connect_timeout}s"
+    except (requests.exceptions.ReadTimeout, ReadTimeoutError):
+        if time.monotonic() >= deadline:
+            return f"Search error: total timeout after {total_timeout}s"
+        return f"Search error: read timeout after {read_timeout}s"
     except Exception as e:
         return f"Search error: {e}"
 